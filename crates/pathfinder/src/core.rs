@@ -3,36 +3,37 @@
 //!
 //! This includes many trivial wrappers around [StarkHash] which help by providing additional type safety.
 use pedersen::StarkHash;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use web3::types::{H160, H256};
 
 /// The address of a StarkNet contract.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct ContractAddress(pub StarkHash);
+pub struct ContractAddress(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// The salt of a StarkNet contract address.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct ContractAddressSalt(pub StarkHash);
+pub struct ContractAddressSalt(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A StarkNet contract's hash. This is a hash over a contract's
 /// deployment properties e.g. code and ABI.
 ///
 /// Not to be confused with [ContractStateHash].
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct ContractHash(pub StarkHash);
+pub struct ContractHash(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A StarkNet contract's state hash. This is the value stored
 /// in the global state tree.
 ///
 /// Not to be confused with [ContractHash].
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ContractStateHash(pub StarkHash);
+pub struct ContractStateHash(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A commitment root of a StarkNet contract. This is the entry-point
 /// for a contract's state at a specific point in time via the contract
 /// state tree.
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ContractRoot(pub StarkHash);
+pub struct ContractRoot(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A Starknet contract's bytecode and ABI.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -43,7 +44,7 @@ pub struct ContractCode {
 
 /// Entry point of a StarkNet `call`.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct EntryPoint(pub StarkHash);
+pub struct EntryPoint(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 impl EntryPoint {
     /// Returns a new EntryPoint which has been truncated to fit from Keccak256 digest of input.
@@ -59,44 +60,105 @@ impl EntryPoint {
 
 /// A single parameter passed to a StarkNet `call`.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct CallParam(pub StarkHash);
+pub struct CallParam(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A single parameter passed to a StarkNet contract constructor.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct ConstructorParam(pub StarkHash);
+pub struct ConstructorParam(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A single result value of a StarkNet `call`.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct CallResultValue(pub StarkHash);
+pub struct CallResultValue(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A single element of a signature used to secure a StarkNet `call`.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct CallSignatureElem(pub StarkHash);
+pub struct CallSignatureElem(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A word from a StarkNet contract bytecode.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct ByteCodeWord(pub StarkHash);
+pub struct ByteCodeWord(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// The address of a storage element for a StarkNet contract.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct StorageAddress(pub StarkHash);
+pub struct StorageAddress(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// The value of a storage element for a StarkNet contract.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct StorageValue(pub StarkHash);
+pub struct StorageValue(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A commitment root of the global StarkNet state. This is the entry-point
 /// for the global state at a specific point in time via the global state tree.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct GlobalRoot(pub StarkHash);
+pub struct GlobalRoot(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A StarkNet block hash.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct StarknetBlockHash(pub StarkHash);
+pub struct StarknetBlockHash(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A StarkNet block number.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
-pub struct StarknetBlockNumber(pub u64);
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct StarknetBlockNumber(#[serde(with = "hex_serde::NumAsHex")] pub u64);
+
+/// Identifies a specific StarkNet block, either by its hash, its number, or a
+/// well-known tag, as accepted by the StarkNet JSON-RPC API and used for
+/// storage lookups throughout the crate.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlockId {
+    Hash(StarknetBlockHash),
+    Number(StarknetBlockNumber),
+    Tag(BlockTag),
+}
+
+/// A symbolic tag for a StarkNet block which does not (yet) have a fixed
+/// hash or number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockTag {
+    Latest,
+    Pending,
+}
+
+impl Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BlockId::Hash(hash) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("block_hash", hash)?;
+                map.end()
+            }
+            BlockId::Number(number) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("block_number", number)?;
+                map.end()
+            }
+            BlockId::Tag(tag) => tag.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Helper {
+            Tag(BlockTag),
+            Hash { block_hash: StarknetBlockHash },
+            Number { block_number: StarknetBlockNumber },
+        }
+
+        Ok(match Helper::deserialize(deserializer)? {
+            Helper::Tag(tag) => BlockId::Tag(tag),
+            Helper::Hash { block_hash } => BlockId::Hash(block_hash),
+            Helper::Number { block_number } => BlockId::Number(block_number),
+        })
+    }
+}
 
 /// The timestamp of a Starknet block.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -104,40 +166,154 @@ pub struct StarknetBlockTimestamp(pub u64);
 
 /// A StarkNet transaction hash.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct StarknetTransactionHash(pub StarkHash);
+pub struct StarknetTransactionHash(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A StarkNet transaction hash.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct StarknetTransactionIndex(pub u64);
+pub struct StarknetTransactionIndex(#[serde(with = "hex_serde::NumAsHex")] pub u64);
 
 /// A single element of a signature used to secure a StarkNet transaction.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct TransactionSignatureElem(pub StarkHash);
+pub struct TransactionSignatureElem(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
+
+/// The maximum amount of a resource and the maximum price per unit of that
+/// resource a v3 transaction is willing to pay, as defined by the StarkNet
+/// JSON-RPC `RESOURCE_BOUNDS` schema.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResourceBounds {
+    #[serde(with = "hex_serde::NumAsHex")]
+    pub max_amount: u64,
+    #[serde(with = "hex_serde::NumAsHex128")]
+    pub max_price_per_unit: u128,
+}
+
+/// The L1-gas and L2-gas [ResourceBounds] of a v3 transaction.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResourceBoundsMapping {
+    pub l1_gas: ResourceBounds,
+    pub l2_gas: ResourceBounds,
+}
+
+/// The tip paid to the sequencer by a v3 transaction, on top of its [ResourceBounds].
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Tip(#[serde(with = "hex_serde::NumAsHex")] pub u64);
+
+/// Data passed along to the paymaster contract by a v3 transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PaymasterData(#[serde(with = "hex_serde::FeltVecAsHex")] pub Vec<StarkHash>);
+
+/// Data generated by the account contract during the execution of a v3
+/// `DEPLOY_ACCOUNT` transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AccountDeploymentData(#[serde(with = "hex_serde::FeltVecAsHex")] pub Vec<StarkHash>);
+
+/// Selects which data availability mode a v3 transaction's nonce or fee
+/// applies to.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum DataAvailabilityMode {
+    L1,
+    L2,
+}
 
 /// A nonce that is added to an L1 to L2 message in a StarkNet transaction.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct L1ToL2MessageNonce(pub StarkHash);
+pub struct L1ToL2MessageNonce(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A single element of the payload of an L1 to L2 message in a StarkNet transaction.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct L1ToL2MessagePayloadElem(pub StarkHash);
+pub struct L1ToL2MessagePayloadElem(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// A single element of the payload of an L2 to L1 message in a StarkNet transaction.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct L2ToL1MessagePayloadElem(pub StarkHash);
+pub struct L2ToL1MessagePayloadElem(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// StarkNet transaction event data.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct EventData(pub StarkHash);
+pub struct EventData(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// StarkNet transaction event key.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
-pub struct EventKey(pub StarkHash);
+pub struct EventKey(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
 
 /// StarkNet protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
 pub struct StarknetProtocolVersion(pub H256);
 
+/// Identifies a StarkNet chain. This is the value used to seed
+/// transaction-hash computation and to select which L1 contracts a client
+/// should talk to, instead of hardcoding the underlying felt at each call site.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ChainId(#[serde(with = "hex_serde::FeltAsHex")] pub StarkHash);
+
+impl ChainId {
+    /// The chain ID of StarkNet mainnet.
+    pub fn mainnet() -> ChainId {
+        ChainId::from_name("SN_MAIN").expect("known chain name is valid")
+    }
+
+    /// The chain ID of the StarkNet Goerli testnet.
+    pub fn goerli_testnet() -> ChainId {
+        ChainId::from_name("SN_GOERLI").expect("known chain name is valid")
+    }
+
+    /// The chain ID of the StarkNet integration environment.
+    pub fn integration() -> ChainId {
+        ChainId::from_name("SN_GOERLI2").expect("known chain name is valid")
+    }
+
+    /// Derives a [ChainId] from an arbitrary ASCII chain name, by
+    /// interpreting the name's bytes as a big-endian felt.
+    ///
+    /// Returns an error instead of panicking if `name` is not ASCII or does
+    /// not fit in a felt, since this may be fed from user-supplied
+    /// configuration for custom or development chains.
+    pub fn from_name(name: &str) -> Result<ChainId, InvalidChainName> {
+        if !name.is_ascii() {
+            return Err(InvalidChainName::NotAscii);
+        }
+        if name.len() > 31 {
+            return Err(InvalidChainName::TooLong);
+        }
+
+        let mut bytes = [0u8; 32];
+        bytes[32 - name.len()..].copy_from_slice(name.as_bytes());
+
+        Ok(ChainId(
+            StarkHash::from_be_bytes(bytes)
+                .expect("ASCII chain name of at most 31 bytes fits in a felt"),
+        ))
+    }
+
+    /// Recovers the ASCII chain name this [ChainId] was derived from.
+    pub fn name(&self) -> String {
+        let bytes = self.0.to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[start..]).into_owned()
+    }
+}
+
+/// The reason [ChainId::from_name] rejected a chain name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidChainName {
+    /// The chain name contains non-ASCII characters.
+    NotAscii,
+    /// The chain name is too long to fit in a felt (more than 31 bytes).
+    TooLong,
+}
+
+impl std::fmt::Display for InvalidChainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidChainName::NotAscii => write!(f, "chain name must be ASCII"),
+            InvalidChainName::TooLong => {
+                write!(f, "chain name must fit in a felt (at most 31 bytes)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidChainName {}
+
 /// An Ethereum address.
 #[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
 pub struct EthereumAddress(pub H160);
@@ -164,17 +340,38 @@ pub struct EthereumLogIndex(pub u64);
 
 impl StarknetBlockNumber {
     pub const GENESIS: StarknetBlockNumber = StarknetBlockNumber(0);
-}
 
-impl std::cmp::PartialOrd for StarknetBlockNumber {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(&other.0)
+    /// Adds `rhs`, returning `None` instead of overflowing.
+    pub fn checked_add(self, rhs: u64) -> Option<StarknetBlockNumber> {
+        self.0.checked_add(rhs).map(Self)
+    }
+
+    /// Subtracts `rhs`, returning `None` instead of underflowing.
+    pub fn checked_sub(self, rhs: u64) -> Option<StarknetBlockNumber> {
+        self.0.checked_sub(rhs).map(Self)
+    }
+
+    /// Subtracts `rhs`, floored at [StarknetBlockNumber::GENESIS] instead of underflowing.
+    pub fn saturating_sub(self, rhs: u64) -> StarknetBlockNumber {
+        Self(self.0.saturating_sub(rhs)).max(Self::GENESIS)
+    }
+
+    /// Returns an iterator over the block numbers in `[self, end)`, i.e. the
+    /// range up to but excluding `end`.
+    ///
+    /// This lets sync code walk towards an unknown chain tip without manual
+    /// `+ 1` loops.
+    pub fn range_to(self, end: StarknetBlockNumber) -> impl Iterator<Item = StarknetBlockNumber> {
+        (self.0..end.0).map(Self)
     }
 }
 
 impl std::ops::Add<u64> for StarknetBlockNumber {
     type Output = StarknetBlockNumber;
 
+    /// Panics on overflow in debug builds (and in release builds via
+    /// `overflow-checks`); prefer [StarknetBlockNumber::checked_add] when
+    /// walking towards an unknown chain tip.
     fn add(self, rhs: u64) -> Self::Output {
         Self(self.0 + rhs)
     }
@@ -189,6 +386,9 @@ impl std::ops::AddAssign<u64> for StarknetBlockNumber {
 impl std::ops::Sub<u64> for StarknetBlockNumber {
     type Output = StarknetBlockNumber;
 
+    /// Panics on underflow in debug builds (and in release builds via
+    /// `overflow-checks`); prefer [StarknetBlockNumber::checked_sub] or
+    /// [StarknetBlockNumber::saturating_sub] when walking towards the genesis block.
     fn sub(self, rhs: u64) -> Self::Output {
         Self(self.0 - rhs)
     }
@@ -217,3 +417,323 @@ impl From<StarknetBlockHash> for crate::rpc::types::BlockHashOrTag {
         crate::rpc::types::BlockHashOrTag::Hash(hash)
     }
 }
+
+/// Serde helpers for the StarkNet JSON-RPC wire encoding of felts and
+/// numeric quantities, which differs from the raw encoding [StarkHash] and
+/// `serde`'s default `u64`/`u128` handling produce.
+///
+/// The spec mandates a lowercase, `0x`-prefixed hex string with no redundant
+/// leading zeros (`^0x(0|[1-9a-f][0-9a-f]*)`) for both `FELT` and
+/// `NUM_AS_HEX` fields.
+pub mod hex_serde {
+    use super::StarkHash;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    fn trimmed_hex(bytes: &[u8]) -> String {
+        let hex = bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        let trimmed = hex.trim_start_matches('0');
+        format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+    }
+
+    fn parse_hex_digits<E: serde::de::Error>(s: &str) -> Result<Vec<u8>, E> {
+        let s = s
+            .strip_prefix("0x")
+            .ok_or_else(|| E::custom("missing 0x prefix"))?;
+        if s.is_empty() {
+            return Err(E::custom("no digits after 0x prefix"));
+        }
+        if s.len() > 1 && s.starts_with('0') {
+            return Err(E::custom("redundant leading zeros in hex string"));
+        }
+        let padded = format!("{:0>width$}", s, width = s.len() + (s.len() % 2));
+        (0..padded.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&padded[i..i + 2], 16).map_err(E::custom))
+            .collect()
+    }
+
+    /// Parses a `0x`-prefixed hex string into a big-endian, zero-padded
+    /// `[u8; N]`, shared by the fixed-width [FeltAsHex]/[NumAsHex]/[NumAsHex128] helpers.
+    fn parse_fixed_hex_bytes<const N: usize, E: serde::de::Error>(s: &str) -> Result<[u8; N], E> {
+        let digits = parse_hex_digits::<E>(s)?;
+        if digits.len() > N {
+            return Err(E::custom(format!("value does not fit in {} bytes", N)));
+        }
+        let mut bytes = [0u8; N];
+        bytes[N - digits.len()..].copy_from_slice(&digits);
+        Ok(bytes)
+    }
+
+    /// (De)serializes a [StarkHash] as a `0x`-prefixed hex string with no
+    /// redundant leading zeros, per the StarkNet JSON-RPC `FELT` format.
+    pub struct FeltAsHex;
+
+    impl FeltAsHex {
+        pub fn serialize<S>(value: &StarkHash, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&trimmed_hex(&value.to_be_bytes()))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<StarkHash, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let bytes = parse_fixed_hex_bytes::<32, D::Error>(&s)?;
+            StarkHash::from_be_bytes(bytes).map_err(serde::de::Error::custom)
+        }
+    }
+
+    /// (De)serializes a `u64` as a `0x`-prefixed hex string with no
+    /// redundant leading zeros, per the StarkNet JSON-RPC `NUM_AS_HEX` format.
+    pub struct NumAsHex;
+
+    impl NumAsHex {
+        pub fn serialize<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&trimmed_hex(&value.to_be_bytes()))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let bytes = parse_fixed_hex_bytes::<8, D::Error>(&s)?;
+            Ok(u64::from_be_bytes(bytes))
+        }
+    }
+
+    /// (De)serializes a `u128` as a `0x`-prefixed hex string with no
+    /// redundant leading zeros, per the StarkNet JSON-RPC `NUM_AS_HEX` format.
+    pub struct NumAsHex128;
+
+    impl NumAsHex128 {
+        pub fn serialize<S>(value: &u128, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&trimmed_hex(&value.to_be_bytes()))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<u128, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let bytes = parse_fixed_hex_bytes::<16, D::Error>(&s)?;
+            Ok(u128::from_be_bytes(bytes))
+        }
+    }
+
+    /// (De)serializes a `Vec<StarkHash>` as a JSON array of [FeltAsHex]-encoded
+    /// strings, per the StarkNet JSON-RPC encoding of felt arrays such as
+    /// `paymaster_data`/`account_deployment_data`.
+    pub struct FeltVecAsHex;
+
+    impl FeltVecAsHex {
+        pub fn serialize<S>(value: &[StarkHash], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            struct Felt<'a>(&'a StarkHash);
+
+            impl<'a> serde::Serialize for Felt<'a> {
+                fn serialize<S2>(&self, serializer: S2) -> Result<S2::Ok, S2::Error>
+                where
+                    S2: Serializer,
+                {
+                    FeltAsHex::serialize(self.0, serializer)
+                }
+            }
+
+            serializer.collect_seq(value.iter().map(Felt))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<StarkHash>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct Felt(StarkHash);
+
+            impl<'de> Deserialize<'de> for Felt {
+                fn deserialize<D2>(deserializer: D2) -> Result<Self, D2::Error>
+                where
+                    D2: Deserializer<'de>,
+                {
+                    FeltAsHex::deserialize(deserializer).map(Felt)
+                }
+            }
+
+            Ok(Vec::<Felt>::deserialize(deserializer)?
+                .into_iter()
+                .map(|felt| felt.0)
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn num_as_hex_round_trip() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Wrapper(#[serde(with = "hex_serde::NumAsHex")] u64);
+
+        for value in [0u64, 1, 42, u64::MAX] {
+            let json = serde_json::to_string(&Wrapper(value)).unwrap();
+            let parsed: Wrapper = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.0, value);
+        }
+
+        assert_eq!(serde_json::to_string(&Wrapper(0)).unwrap(), r#""0x0""#);
+        assert_eq!(serde_json::to_string(&Wrapper(42)).unwrap(), r#""0x2a""#);
+    }
+
+    #[test]
+    fn felt_as_hex_round_trip() {
+        let zero = StarknetBlockHash(StarkHash::ZERO);
+        let small = StarknetBlockHash(StarkHash::from_hex_str("0x1234").unwrap());
+        // The StarkNet field prime minus one: a genuine 63-nibble felt, one
+        // nibble wider than a 62-nibble value, exercising the odd-length
+        // padding path in `parse_hex_digits`.
+        let max = StarknetBlockHash(
+            StarkHash::from_hex_str(
+                "0x800000000000011000000000000000000000000000000000000000000000000",
+            )
+            .unwrap(),
+        );
+
+        for hash in [zero, small, max] {
+            let json = serde_json::to_string(&hash).unwrap();
+            let parsed: StarknetBlockHash = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, hash);
+        }
+
+        assert_eq!(serde_json::to_string(&zero).unwrap(), r#""0x0""#);
+        assert_eq!(serde_json::to_string(&small).unwrap(), r#""0x1234""#);
+        assert_eq!(
+            serde_json::to_string(&max).unwrap(),
+            r#""0x800000000000011000000000000000000000000000000000000000000000000""#
+        );
+    }
+
+    #[test]
+    fn v3_newtypes_hex_round_trip() {
+        let tip = Tip(42);
+        assert_eq!(serde_json::to_string(&tip).unwrap(), r#""0x2a""#);
+        assert_eq!(serde_json::from_str::<Tip>(r#""0x2a""#).unwrap(), tip);
+
+        let bounds = ResourceBounds {
+            max_amount: 1,
+            max_price_per_unit: u128::MAX,
+        };
+        let json = serde_json::to_string(&bounds).unwrap();
+        assert_eq!(
+            json,
+            r#"{"max_amount":"0x1","max_price_per_unit":"0xffffffffffffffffffffffffffffffff"}"#
+        );
+        assert_eq!(serde_json::from_str::<ResourceBounds>(&json).unwrap(), bounds);
+
+        let data = PaymasterData(vec![StarkHash::ZERO, StarkHash::from_hex_str("0x1234").unwrap()]);
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(json, r#"["0x0","0x1234"]"#);
+        assert_eq!(serde_json::from_str::<PaymasterData>(&json).unwrap(), data);
+    }
+
+    #[test]
+    fn chain_id_name_round_trip() {
+        for name in ["SN_MAIN", "SN_GOERLI", "SN_GOERLI2"] {
+            assert_eq!(ChainId::from_name(name).unwrap().name(), name);
+        }
+
+        assert_eq!(ChainId::mainnet(), ChainId::from_name("SN_MAIN").unwrap());
+        assert_eq!(
+            ChainId::goerli_testnet(),
+            ChainId::from_name("SN_GOERLI").unwrap()
+        );
+        assert_eq!(
+            ChainId::integration(),
+            ChainId::from_name("SN_GOERLI2").unwrap()
+        );
+    }
+
+    #[test]
+    fn chain_id_from_name_rejects_invalid_input() {
+        assert_eq!(
+            ChainId::from_name("not-ascii-\u{1234}"),
+            Err(InvalidChainName::NotAscii)
+        );
+        assert_eq!(
+            ChainId::from_name("a".repeat(32).as_str()),
+            Err(InvalidChainName::TooLong)
+        );
+    }
+
+    #[test]
+    fn block_number_checked_add_sub() {
+        let genesis = StarknetBlockNumber::GENESIS;
+
+        assert_eq!(genesis.checked_sub(1), None);
+        assert_eq!(genesis.checked_add(1), Some(StarknetBlockNumber(1)));
+        assert_eq!(StarknetBlockNumber(u64::MAX).checked_add(1), None);
+        assert_eq!(genesis.saturating_sub(1), genesis);
+        assert_eq!(StarknetBlockNumber(5).saturating_sub(1), StarknetBlockNumber(4));
+
+        assert!(StarknetBlockNumber(1) < StarknetBlockNumber(2));
+    }
+
+    #[test]
+    fn block_number_range_to() {
+        let blocks = StarknetBlockNumber(1)
+            .range_to(StarknetBlockNumber(4))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            blocks,
+            vec![
+                StarknetBlockNumber(1),
+                StarknetBlockNumber(2),
+                StarknetBlockNumber(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn block_id_round_trip() {
+        let hash = BlockId::Hash(StarknetBlockHash(StarkHash::from_hex_str("0x1234").unwrap()));
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, r#"{"block_hash":"0x1234"}"#);
+        assert_eq!(serde_json::from_str::<BlockId>(&json).unwrap(), hash);
+
+        let number = BlockId::Number(StarknetBlockNumber(5));
+        let json = serde_json::to_string(&number).unwrap();
+        assert_eq!(json, r#"{"block_number":"0x5"}"#);
+        assert_eq!(serde_json::from_str::<BlockId>(&json).unwrap(), number);
+
+        let latest = BlockId::Tag(BlockTag::Latest);
+        assert_eq!(serde_json::to_string(&latest).unwrap(), r#""latest""#);
+        assert_eq!(serde_json::from_str::<BlockId>(r#""latest""#).unwrap(), latest);
+
+        let pending = BlockId::Tag(BlockTag::Pending);
+        assert_eq!(serde_json::to_string(&pending).unwrap(), r#""pending""#);
+        assert_eq!(
+            serde_json::from_str::<BlockId>(r#""pending""#).unwrap(),
+            pending
+        );
+    }
+
+    #[test]
+    fn block_id_rejects_malformed_input() {
+        assert!(serde_json::from_str::<BlockId>(r#""unknown""#).is_err());
+        assert!(serde_json::from_str::<BlockId>(r#"{}"#).is_err());
+        assert!(serde_json::from_str::<BlockId>(r#"{"block_number":5}"#).is_err());
+        assert!(serde_json::from_str::<BlockId>(r#"{"block_hash":"not-hex"}"#).is_err());
+    }
+}